@@ -12,11 +12,46 @@ use num::Zero;
 use image::ColorType;
 use image::png::PNGEncoder;
 
-fn calculate_escape_time(c: Complex<f64>, limit: u32) -> Option<u32> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FractalKind {
+    Mandelbrot,
+    Mandelbrot3,
+    BurningShip,
+}
+
+impl FromStr for FractalKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mandelbrot" => Ok(FractalKind::Mandelbrot),
+            "mandelbrot3" => Ok(FractalKind::Mandelbrot3),
+            "burning-ship" => Ok(FractalKind::BurningShip),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn test_fractal_kind_from_str() {
+    assert_eq!(FractalKind::from_str("mandelbrot"), Ok(FractalKind::Mandelbrot));
+    assert_eq!(FractalKind::from_str("mandelbrot3"), Ok(FractalKind::Mandelbrot3));
+    assert_eq!(FractalKind::from_str("burning-ship"), Ok(FractalKind::BurningShip));
+    assert_eq!(FractalKind::from_str("nope"), Err(()));
+}
+
+fn calculate_escape_time(c: Complex<f64>, limit: u32, kind: FractalKind) -> Option<u32> {
     let mut z = Complex::zero();
 
     for i in 0 .. limit {
-        z = z * z + c;
+        z = match kind {
+            FractalKind::Mandelbrot => z * z + c,
+            FractalKind::Mandelbrot3 => z * z * z + c,
+            FractalKind::BurningShip => {
+                let folded = Complex { re: z.re.abs(), im: z.im.abs() };
+                folded * folded + c
+            }
+        };
 
         if z.norm() > 4.0 {
             return Some(i);
@@ -26,6 +61,24 @@ fn calculate_escape_time(c: Complex<f64>, limit: u32) -> Option<u32> {
     None
 }
 
+#[test]
+fn test_calculate_escape_time_mandelbrot3() {
+    assert_eq!(calculate_escape_time(Complex { re: 0.0, im: 0.0 }, 50, FractalKind::Mandelbrot3),
+               None);
+    assert_eq!(calculate_escape_time(Complex { re: 2.0, im: 0.0 }, 50, FractalKind::Mandelbrot3),
+               Some(1));
+    assert_eq!(calculate_escape_time(Complex { re: -0.5, im: 0.5 }, 50, FractalKind::Mandelbrot3),
+               None);
+}
+
+#[test]
+fn test_calculate_escape_time_burning_ship() {
+    assert_eq!(calculate_escape_time(Complex { re: -1.0, im: 0.0 }, 50, FractalKind::BurningShip),
+               None);
+    assert_eq!(calculate_escape_time(Complex { re: -0.5, im: 0.5 }, 50, FractalKind::BurningShip),
+               Some(3));
+}
+
 fn parse_pair<T: FromStr>(s: &str, separator: char) -> Option<(T,T)> {
     match s.find(separator) {
         None => None,
@@ -89,7 +142,8 @@ fn test_pixel_to_point() {
 fn render(pixels: &mut [u8],
           bounds: (usize, usize),
           upper_left: Complex<f64>,
-          lower_right: Complex<f64>) {
+          lower_right: Complex<f64>,
+          kind: FractalKind) {
 
     assert_eq!(pixels.len(), bounds.0 * bounds.1);
 
@@ -100,7 +154,7 @@ fn render(pixels: &mut [u8],
                                        upper_left,
                                        lower_right);
 
-            pixels[row * bounds.0 + column] = match calculate_escape_time(point, 255) {
+            pixels[row * bounds.0 + column] = match calculate_escape_time(point, 255, kind) {
                 None => 0,
                 Some(count) => 255 - count as u8,
             }
@@ -120,15 +174,16 @@ fn write_image(filename: &str, pixels: &[u8], bounds: (usize, usize)) -> Result<
 }
 
 fn print_help_and_exit() -> ! {
-    eprintln!("Usage: <file_to_be_saved> <bounds> <upper_left> <lower_right>");
-    eprintln!("Example:\ncargo run --release -- fractal.png 1000x750 -1.20,0.35 -1,0.20");
+    eprintln!("Usage: <file_to_be_saved> <bounds> <upper_left> <lower_right> <fractal_kind>");
+    eprintln!("<fractal_kind> is one of: mandelbrot, mandelbrot3, burning-ship");
+    eprintln!("Example:\ncargo run --release -- fractal.png 1000x750 -1.20,0.35 -1,0.20 mandelbrot");
     std::process::exit(1);
 }
 
 fn read_args() -> Vec<String> {
     let args: Vec<String> = std::env::args().collect();
 
-    if args.len() != 5 {
+    if args.len() != 6 {
         print_help_and_exit();
     }
 
@@ -139,6 +194,7 @@ fn save_fractal(args: Vec<String>) {
     let bounds = parse_pair(&args[2], 'x').expect("error parsing bounds");
     let upper_left = parse_complex(&args[3]).expect("error parsing upper left corner");
     let lower_right = parse_complex(&args[4]).expect("error parsing lower right corner");
+    let kind = FractalKind::from_str(&args[5]).expect("error parsing fractal kind");
     let mut pixels = vec![0; bounds.0 * bounds.1];
     let threads = num_cpus::get();
     let rows_per_band = bounds.1 / threads + 1;
@@ -156,7 +212,7 @@ fn save_fractal(args: Vec<String>) {
                                                                     upper_left, lower_right);
 
                 spawner.spawn(move || {
-                   render(band,band_bounds, band_upper_left, band_lower_right);
+                   render(band, band_bounds, band_upper_left, band_lower_right, kind);
                 });
             }
         });